@@ -1,5 +1,242 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
-use tauri::Manager;
+use std::fs;
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::image::Image;
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{Emitter, Manager, PhysicalPosition, PhysicalSize, WindowEvent};
+use tauri_plugin_cli::CliExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+const TRAY_ICON_SIZE: u32 = 16;
+const TRAY_ICON_RGBA: &[u8] = include_bytes!("../icons/tray-icon.rgba");
+
+const WINDOW_STATE_FILE: &str = "window-state.json";
+
+/// 디스크에 저장/복원되는 메인 창 상태
+#[derive(Serialize, Deserialize)]
+struct WindowState {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+    always_on_top: bool,
+}
+
+/// 현재 등록된 전역 단축키를 보관하는 상태
+/// Why: 런타임에 재등록할 때 이전 단축키를 해제하려면 값을 기억해야 함
+struct HotkeyState(Mutex<String>);
+
+const DEFAULT_TOGGLE_ACCELERATOR: &str = "CmdOrCtrl+Shift+Q";
+const HOTKEY_STATE_FILE: &str = "hotkey-state.json";
+
+/// 디스크에 저장/복원되는 토글 단축키 설정
+#[derive(Serialize, Deserialize)]
+struct HotkeyConfig {
+    accelerator: String,
+}
+
+/// 단축키 설정 JSON 파일의 경로 (앱 config 디렉터리 아래)
+fn hotkey_state_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(HOTKEY_STATE_FILE))
+}
+
+/// 선택된 토글 단축키를 저장해 재시작 후에도 유지되게 함
+fn save_hotkey_state(app: &tauri::AppHandle, accelerator: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&HotkeyConfig {
+        accelerator: accelerator.to_string(),
+    })
+    .map_err(|e| e.to_string())?;
+    fs::write(hotkey_state_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// 저장된 토글 단축키를 읽어옴 (없거나 손상된 경우 기본값 사용)
+fn load_hotkey_state(app: &tauri::AppHandle) -> String {
+    hotkey_state_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str::<HotkeyConfig>(&json).ok())
+        .map(|config| config.accelerator)
+        .unwrap_or_else(|| DEFAULT_TOGGLE_ACCELERATOR.to_string())
+}
+
+/// 메인 창 토글 단축키를 등록 (실패 시 이전 등록 상태는 그대로 둠)
+fn register_toggle_shortcut(app: &tauri::AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator.parse().map_err(|e| format!("{e}"))?;
+    let fired_accelerator = accelerator.to_string();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                toggle_main_window(app);
+                let _ = app.emit("hotkey://toggle-fired", fired_accelerator.clone());
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// CLI 플래그로 넘어온, 시작 시 적용할 런치 옵션
+/// Why: 스플래시 완료 후 메인 창을 보여주는 지점에서 `--hidden` 여부를 다시 확인해야 함
+struct LaunchOptions {
+    start_hidden: Mutex<bool>,
+}
+
+/// `--geometry WxH+X+Y` 문자열을 파싱
+fn parse_geometry(geometry: &str) -> Option<(u32, u32, i32, i32)> {
+    let (size, pos) = geometry.split_once('+').map_or((geometry, None), |(s, rest)| {
+        (s, Some(rest))
+    });
+    let (width, height) = size.split_once('x')?;
+    let width: u32 = width.parse().ok()?;
+    let height: u32 = height.parse().ok()?;
+
+    let Some(pos) = pos else {
+        return Some((width, height, 0, 0));
+    };
+    let (x, y) = pos.split_once('+')?;
+    let x: i32 = x.parse().ok()?;
+    let y: i32 = y.parse().ok()?;
+    Some((width, height, x, y))
+}
+
+/// CLI 인자를 파싱해 메인 창에 적용 (인자가 없으면 아무 것도 하지 않음)
+fn apply_cli_args(app: &tauri::AppHandle) {
+    let matches = match app.cli().matches() {
+        Ok(matches) => matches,
+        Err(_) => return,
+    };
+
+    if matches!(matches.args.get("always-on-top"), Some(arg) if arg.value == serde_json::Value::Bool(true))
+    {
+        let _ = apply_always_on_top(app, true);
+    }
+
+    if matches!(matches.args.get("hidden"), Some(arg) if arg.value == serde_json::Value::Bool(true))
+    {
+        if let Some(state) = app.try_state::<LaunchOptions>() {
+            if let Ok(mut start_hidden) = state.start_hidden.lock() {
+                *start_hidden = true;
+            }
+        }
+    }
+
+    if let Some(arg) = matches.args.get("geometry") {
+        if let Some(geometry) = arg.value.as_str() {
+            match parse_geometry(geometry) {
+                Some((width, height, x, y)) => {
+                    if let Some(window) = app.get_webview_window("main") {
+                        let _ = window.set_size(PhysicalSize::new(width, height));
+                        let _ = window.set_position(PhysicalPosition::new(x, y));
+                    }
+                }
+                None => eprintln!("warning: ignoring malformed --geometry value: {geometry}"),
+            }
+        }
+    }
+}
+
+/// 트레이/메뉴의 "Always on Top" 체크 항목
+/// Why: 프런트·트레이·메뉴 어느 쪽에서 상태가 바뀌어도 체크 표시를 같이 갱신해야 함
+struct TrayMenuState {
+    always_on_top: CheckMenuItem<tauri::Wry>,
+}
+
+/// "항상 위에" 상태를 바꾸고 트레이 체크 표시 및 프런트 이벤트를 동기화
+fn apply_always_on_top(app: &tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window("main") {
+        window.set_always_on_top(enabled).map_err(|e| e.to_string())?;
+    }
+    if let Some(state) = app.try_state::<TrayMenuState>() {
+        state
+            .always_on_top
+            .set_checked(enabled)
+            .map_err(|e| e.to_string())?;
+    }
+    let _ = app.emit("tray://toggle-top", enabled);
+    let _ = app.emit("window://always-on-top-changed", enabled);
+    Ok(())
+}
+
+/// 창 상태 JSON 파일의 경로 (앱 config 디렉터리 아래)
+fn window_state_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(WINDOW_STATE_FILE))
+}
+
+/// 현재 모니터 배치에 맞춰 좌표를 화면 밖으로 나가지 않게 자름
+/// Why: 디스플레이 구성이 바뀐 뒤 복원하면 꺼진 모니터 좌표에 창이 열려 보이지 않을 수 있음
+fn clamp_to_monitors(window: &tauri::WebviewWindow, x: i32, y: i32, width: u32, height: u32) -> (i32, i32) {
+    let monitors = window.available_monitors().unwrap_or_default();
+    let fits = monitors.iter().any(|m| {
+        let pos = m.position();
+        let size = m.size();
+        x >= pos.x && y >= pos.y && x < pos.x + size.width as i32 && y < pos.y + size.height as i32
+    });
+    if fits {
+        return (x, y);
+    }
+    let Some(primary) = monitors.first() else {
+        return (0, 0);
+    };
+    let pos = primary.position();
+    let size = primary.size();
+    let max_x = pos.x + (size.width as i32 - width as i32).max(0);
+    let max_y = pos.y + (size.height as i32 - height as i32).max(0);
+    let clamped_x = x.max(pos.x).min(max_x);
+    let clamped_y = y.max(pos.y).min(max_y);
+    (clamped_x, clamped_y)
+}
+
+/// 메인 창의 위치/크기/최대화/항상 위에 상태를 JSON 파일로 저장
+#[tauri::command]
+fn save_window_state(app: tauri::AppHandle) -> Result<(), String> {
+    let Some(window) = app.get_webview_window("main") else {
+        return Ok(());
+    };
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+    let state = WindowState {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized: window.is_maximized().map_err(|e| e.to_string())?,
+        always_on_top: window.is_always_on_top().map_err(|e| e.to_string())?,
+    };
+    let json = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
+    fs::write(window_state_path(&app)?, json).map_err(|e| e.to_string())
+}
+
+/// 저장된 창 상태를 읽어 메인 창에 적용 (좌표는 현재 모니터 배치로 클램프)
+#[tauri::command]
+fn restore_window_state(app: tauri::AppHandle) -> Result<(), String> {
+    let Some(window) = app.get_webview_window("main") else {
+        return Ok(());
+    };
+    let path = window_state_path(&app)?;
+    let Ok(json) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let state: WindowState = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+    let (x, y) = clamp_to_monitors(&window, state.x, state.y, state.width, state.height);
+
+    window
+        .set_size(PhysicalSize::new(state.width, state.height))
+        .map_err(|e| e.to_string())?;
+    window
+        .set_position(PhysicalPosition::new(x, y))
+        .map_err(|e| e.to_string())?;
+    if state.maximized {
+        window.maximize().map_err(|e| e.to_string())?;
+    }
+    apply_always_on_top(&app, state.always_on_top)?;
+    Ok(())
+}
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -9,11 +246,8 @@ fn greet(name: &str) -> String {
 /// "항상 위에" 토글 명령
 /// Why: 사용자가 창을 항상 다른 창 위에 표시할지 선택 가능
 #[tauri::command]
-fn set_always_on_top(window: tauri::Window, enabled: bool) -> Result<(), String> {
-    window
-        .set_always_on_top(enabled)
-        .map_err(|e| e.to_string())?;
-    Ok(())
+fn set_always_on_top(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    apply_always_on_top(&app, enabled)
 }
 
 /// 현재 "항상 위에" 상태 조회
@@ -24,12 +258,231 @@ fn is_always_on_top(window: tauri::Window) -> Result<bool, String> {
         .map_err(|e| e.to_string())
 }
 
+/// 스플래시 화면 뒤에서 돌아가는 초기화 작업
+/// Why: 무거운 시작 작업을 `setup()`에서 동기로 처리하면 첫 화면이 멈춰 보임
+async fn run_startup_tasks() {
+    // TODO: 실제 초기화 작업(설정 로드, DB 연결 등)을 여기에 추가
+}
+
+/// 백엔드 초기화와 프런트엔드 준비, 둘 다 끝났는지 추적하는 상태
+/// Why: 둘 중 먼저 끝난 쪽이 바로 메인 창을 보여주면, 아직 안 끝난 쪽의 초기화가 끝나기 전에
+/// 화면이 떠버리는 경쟁 상태가 생김 — 더 늦게 끝나는 쪽이 전환을 트리거해야 함
+struct StartupGate {
+    frontend_ready: Mutex<bool>,
+    backend_ready: Mutex<bool>,
+}
+
+/// 양쪽이 모두 준비됐을 때만 스플래시를 닫고 메인 창을 보여줌
+fn try_finish_startup(app: &tauri::AppHandle) {
+    let Some(gate) = app.try_state::<StartupGate>() else {
+        return;
+    };
+    let frontend_ready = gate.frontend_ready.lock().map(|r| *r).unwrap_or(false);
+    let backend_ready = gate.backend_ready.lock().map(|r| *r).unwrap_or(false);
+    if !frontend_ready || !backend_ready {
+        return;
+    }
+
+    if let Some(splashscreen) = app.get_webview_window("splashscreen") {
+        let _ = splashscreen.close();
+    }
+    let start_hidden = app
+        .try_state::<LaunchOptions>()
+        .map(|s| s.start_hidden.lock().map(|v| *v).unwrap_or(false))
+        .unwrap_or(false);
+    if !start_hidden {
+        if let Some(main) = app.get_webview_window("main") {
+            let _ = main.show();
+            let _ = main.set_focus();
+        }
+    }
+}
+
+/// 프런트엔드가 준비되면 호출 — 백엔드 초기화도 끝났을 때만 실제 전환이 일어남
+/// Why: 초기화 작업과 프런트 렌더링 중 더 늦게 끝나는 쪽에 맞춰 전환해야 화면이 끊기지 않음
+#[tauri::command]
+fn close_splashscreen(app: tauri::AppHandle) -> Result<(), String> {
+    if let Some(gate) = app.try_state::<StartupGate>() {
+        *gate.frontend_ready.lock().map_err(|e| e.to_string())? = true;
+    }
+    try_finish_startup(&app);
+    Ok(())
+}
+
+/// 메인 창을 보이는 상태와 숨긴 상태 사이에서 토글
+fn toggle_main_window(app: &tauri::AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let is_visible = window.is_visible().unwrap_or(false);
+    if is_visible {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// 새 accelerator를 먼저 등록하고, 성공한 뒤에만 이전 accelerator를 해제
+/// Why: 중복/잘못된 accelerator는 패닉 대신 에러로 돌려주되, 실패해도 기존 단축키는 계속 동작해야 함
+#[tauri::command]
+fn set_global_hotkey(app: tauri::AppHandle, accelerator: String) -> Result<(), String> {
+    let state = app.state::<HotkeyState>();
+    let previous = state.0.lock().map_err(|e| e.to_string())?.clone();
+
+    if previous == accelerator {
+        return Ok(());
+    }
+
+    register_toggle_shortcut(&app, &accelerator)?;
+
+    if app.global_shortcut().is_registered(previous.as_str()) {
+        if let Err(e) = app.global_shortcut().unregister(previous.as_str()) {
+            let _ = app.global_shortcut().unregister(accelerator.as_str());
+            return Err(e.to_string());
+        }
+    }
+
+    save_hotkey_state(&app, &accelerator)?;
+    *state.0.lock().map_err(|e| e.to_string())? = accelerator;
+    Ok(())
+}
+
+/// 트레이 아이콘과 메뉴를 구성하고 이벤트 핸들러를 연결
+fn build_tray(app: &tauri::AppHandle) -> tauri::Result<()> {
+    let is_on_top = app
+        .get_webview_window("main")
+        .and_then(|w| w.is_always_on_top().ok())
+        .unwrap_or(false);
+
+    let show_hide = MenuItem::with_id(app, "show_hide", "Show/Hide", true, None::<&str>)?;
+    let always_on_top =
+        CheckMenuItem::with_id(app, "always_on_top", "Always on Top", true, is_on_top, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    let tray_menu = Menu::with_items(app, &[&show_hide, &always_on_top, &quit])?;
+
+    app.manage(TrayMenuState {
+        always_on_top: always_on_top.clone(),
+    });
+
+    TrayIconBuilder::new()
+        .icon(Image::new(TRAY_ICON_RGBA, TRAY_ICON_SIZE, TRAY_ICON_SIZE))
+        .menu(&tray_menu)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show_hide" => toggle_main_window(app),
+            "always_on_top" => {
+                let enabled = app
+                    .get_webview_window("main")
+                    .and_then(|w| w.is_always_on_top().ok())
+                    .unwrap_or(false);
+                let _ = apply_always_on_top(app, !enabled);
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// 창 메뉴(앱 메뉴)를 구성
+fn build_window_menu(app: &tauri::AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let show_hide = MenuItem::with_id(app, "window_show_hide", "Show/Hide", true, None::<&str>)?;
+    let quit = PredefinedMenuItem::quit(app, None)?;
+    Menu::with_items(app, &[&show_hide, &quit])
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_http::init())
-        .invoke_handler(tauri::generate_handler![greet, set_always_on_top, is_always_on_top])
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_cli::init())
+        .manage(HotkeyState(Mutex::new(DEFAULT_TOGGLE_ACCELERATOR.to_string())))
+        .manage(LaunchOptions { start_hidden: Mutex::new(false) })
+        .manage(StartupGate {
+            frontend_ready: Mutex::new(false),
+            backend_ready: Mutex::new(false),
+        })
+        .setup(|app| {
+            let persisted_accelerator = load_hotkey_state(app.handle());
+            register_toggle_shortcut(app.handle(), &persisted_accelerator)?;
+            *app.state::<HotkeyState>().0.lock().map_err(|e| e.to_string())? = persisted_accelerator;
+
+            let hide_on_esc: Shortcut = "Esc".parse()?;
+
+            app.global_shortcut().on_shortcut(hide_on_esc, move |app, _shortcut, event| {
+                if event.state() != ShortcutState::Pressed {
+                    return;
+                }
+                if let Some(window) = app.get_webview_window("main") {
+                    if window.is_focused().unwrap_or(false) {
+                        let _ = window.hide();
+                    }
+                }
+            })?;
+
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                run_startup_tasks().await;
+                if let Some(gate) = handle.try_state::<StartupGate>() {
+                    if let Ok(mut ready) = gate.backend_ready.lock() {
+                        *ready = true;
+                    }
+                }
+                try_finish_startup(&handle);
+            });
+
+            build_tray(app.handle())?;
+            let window_menu = build_window_menu(app.handle())?;
+            app.set_menu(window_menu)?;
+
+            let _ = restore_window_state(app.handle().clone());
+            apply_cli_args(app.handle());
+
+            if let Some(main) = app.get_webview_window("main") {
+                let main_for_close = main.clone();
+                let handle_for_events = app.handle().clone();
+                main.on_window_event(move |event| {
+                    match event {
+                        WindowEvent::CloseRequested { api, .. } => {
+                            let _ = save_window_state(handle_for_events.clone());
+                            api.prevent_close();
+                            let _ = main_for_close.hide();
+                        }
+                        WindowEvent::Moved(_) => {
+                            let _ = save_window_state(handle_for_events.clone());
+                        }
+                        WindowEvent::Resized(size) => {
+                            let _ = save_window_state(handle_for_events.clone());
+                            let _ = handle_for_events.emit("window://resized", size);
+                        }
+                        WindowEvent::Focused(is_focused) => {
+                            let _ = handle_for_events.emit("window://focus-changed", is_focused);
+                        }
+                        _ => {}
+                    }
+                });
+            }
+
+            Ok(())
+        })
+        .on_menu_event(|app, event| {
+            if event.id.as_ref() == "window_show_hide" {
+                toggle_main_window(app);
+            }
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            set_always_on_top,
+            is_always_on_top,
+            set_global_hotkey,
+            close_splashscreen,
+            save_window_state,
+            restore_window_state
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }